@@ -0,0 +1,139 @@
+use crate::api::client::TweetyClient;
+use crate::api::error::TweetyError;
+use reqwest::Client;
+use reqwest_oauth1::{self, OAuthClientProvider};
+use std::collections::HashMap;
+
+const REQUEST_TOKEN_URL: &str = "https://api.twitter.com/oauth/request_token";
+const AUTHORIZE_URL: &str = "https://api.twitter.com/oauth/authorize";
+const ACCESS_TOKEN_URL: &str = "https://api.twitter.com/oauth/access_token";
+
+/// A temporary OAuth 1.0a request token, obtained from Twitter as the first
+/// step of the PIN-based three-legged authorization flow.
+///
+/// Hand the URL from [`RequestToken::authorize_url`] to the user, have them
+/// open it in a browser, and pass the PIN they're given to
+/// [`RequestToken::complete`] to receive a fully initialized
+/// [`TweetyClient`].
+#[derive(Debug, Clone)]
+pub struct RequestToken {
+    consumer_key: String,
+    consumer_key_secret: String,
+    oauth_token: String,
+    oauth_token_secret: String,
+}
+
+impl RequestToken {
+    /// The URL the user must open in a browser to authorize the app and be
+    /// given a PIN.
+    pub fn authorize_url(&self) -> String {
+        format!("{}?oauth_token={}", AUTHORIZE_URL, self.oauth_token)
+    }
+
+    /// Exchanges the PIN the user obtained from [`RequestToken::authorize_url`]
+    /// for a permanent access token, completing the handshake.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use tweety_rs::api::client::TweetyClient;
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let request_token = TweetyClient::begin_auth("consumer_key", "consumer_secret").await?;
+    /// println!("Open {} and enter the PIN", request_token.authorize_url());
+    /// let client = request_token.complete("123456").await?;
+    /// # let _ = client;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn complete(&self, pin: &str) -> Result<TweetyClient, TweetyError> {
+        let secrets = reqwest_oauth1::Secrets::new(&self.consumer_key, &self.consumer_key_secret)
+            .token(&self.oauth_token, &self.oauth_token_secret);
+
+        let client = Client::new();
+        let response = client
+            .oauth1(secrets)
+            .post(ACCESS_TOKEN_URL)
+            .form(&[("oauth_verifier", pin)])
+            .send()
+            .await
+            .map_err(|err| TweetyError::NetworkError(err.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(TweetyError::ApiError(format!("HTTP {}", response.status())));
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|err| TweetyError::NetworkError(err.to_string()))?;
+        let params = parse_form_encoded(&body);
+
+        let access_token = params
+            .get("oauth_token")
+            .ok_or_else(|| TweetyError::AuthError("missing oauth_token in response".to_string()))?;
+        let access_token_secret = params.get("oauth_token_secret").ok_or_else(|| {
+            TweetyError::AuthError("missing oauth_token_secret in response".to_string())
+        })?;
+
+        Ok(TweetyClient::new(
+            &self.consumer_key,
+            access_token,
+            &self.consumer_key_secret,
+            access_token_secret,
+        ))
+    }
+}
+
+impl TweetyClient {
+    /// Starts the three-legged PIN-based OAuth 1.0a handshake for an app that
+    /// only has a consumer key/secret and no user access token yet.
+    ///
+    /// Returns a [`RequestToken`] whose [`RequestToken::authorize_url`] the
+    /// user should open, and whose [`RequestToken::complete`] exchanges the
+    /// resulting PIN for a ready-to-use `TweetyClient`.
+    pub async fn begin_auth(
+        consumer_key: &str,
+        consumer_key_secret: &str,
+    ) -> Result<RequestToken, TweetyError> {
+        let secrets = reqwest_oauth1::Secrets::new(consumer_key, consumer_key_secret);
+
+        let client = Client::new();
+        let response = client
+            .oauth1(secrets)
+            .post(REQUEST_TOKEN_URL)
+            .form(&[("oauth_callback", "oob")])
+            .send()
+            .await
+            .map_err(|err| TweetyError::NetworkError(err.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(TweetyError::ApiError(format!("HTTP {}", response.status())));
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|err| TweetyError::NetworkError(err.to_string()))?;
+        let params = parse_form_encoded(&body);
+
+        let oauth_token = params
+            .get("oauth_token")
+            .ok_or_else(|| TweetyError::AuthError("missing oauth_token in response".to_string()))?;
+        let oauth_token_secret = params.get("oauth_token_secret").ok_or_else(|| {
+            TweetyError::AuthError("missing oauth_token_secret in response".to_string())
+        })?;
+
+        Ok(RequestToken {
+            consumer_key: consumer_key.to_string(),
+            consumer_key_secret: consumer_key_secret.to_string(),
+            oauth_token: oauth_token.to_string(),
+            oauth_token_secret: oauth_token_secret.to_string(),
+        })
+    }
+}
+
+fn parse_form_encoded(body: &str) -> HashMap<String, String> {
+    url::form_urlencoded::parse(body.as_bytes())
+        .into_owned()
+        .collect()
+}
@@ -0,0 +1,86 @@
+use crate::api::client::TweetyClient;
+use crate::api::credentials::Credentials;
+use crate::api::error::TweetyError;
+use reqwest::{Client, Proxy};
+use std::time::Duration;
+
+/// Builds a [`TweetyClient`] backed by a single, connection-pooled
+/// [`reqwest::Client`] tuned for the caller's needs, instead of the default
+/// one `TweetyClient::new` constructs. The pooled client (and its
+/// kept-alive HTTPS connections) is then reused across every request the
+/// resulting `TweetyClient` makes.
+#[derive(Debug, Default)]
+pub struct TweetyClientBuilder {
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    proxy: Option<Proxy>,
+    user_agent: Option<String>,
+    pool_max_idle_per_host: Option<usize>,
+}
+
+impl TweetyClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the total request timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing the underlying TCP/TLS connection.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Routes requests through the given proxy.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Overrides the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Caps the number of idle, pooled connections kept open per host.
+    pub fn pool_max_idle_per_host(mut self, pool_max_idle_per_host: usize) -> Self {
+        self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+        self
+    }
+
+    /// Finishes the builder, constructing the shared HTTP client and
+    /// pairing it with the given credentials.
+    pub fn build(self, credentials: Credentials) -> Result<TweetyClient, TweetyError> {
+        let mut builder = Client::builder();
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        }
+
+        let http_client = builder
+            .build()
+            .map_err(|err| TweetyError::ClientBuildError(err.to_string()))?;
+
+        Ok(TweetyClient {
+            credentials,
+            http_client,
+        })
+    }
+}
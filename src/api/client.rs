@@ -1,3 +1,5 @@
+use crate::api::builder::TweetyClientBuilder;
+use crate::api::credentials::Credentials;
 use crate::api::error::TweetyError;
 use reqwest::{header::HeaderMap, Client, Method};
 use reqwest_oauth1::{self, OAuthClientProvider};
@@ -5,12 +7,10 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use url::Url;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug)]
 pub struct TweetyClient {
-    pub(crate) consumer_key: String,
-    pub(crate) access_token: String,
-    pub(crate) consumer_key_secret: String,
-    pub(crate) access_token_secret: String,
+    pub(crate) credentials: Credentials,
+    pub(crate) http_client: Client,
 }
 
 pub struct ResponseWithHeaders {
@@ -19,7 +19,8 @@ pub struct ResponseWithHeaders {
 }
 
 impl TweetyClient {
-    /// Creates a new `TweetyClient` instance with the given credentials.
+    /// Creates a new `TweetyClient` instance authenticated with OAuth 1.0a,
+    /// given the credentials.
     ///
     /// # Parameters
     ///
@@ -46,18 +47,192 @@ impl TweetyClient {
         access_token_secret: &str,
     ) -> Self {
         TweetyClient {
-            consumer_key: consumer_key.to_string(),
-            access_token: access_token.to_string(),
-            consumer_key_secret: consumer_key_secret.to_string(),
-            access_token_secret: access_token_secret.to_string(),
+            credentials: Credentials::OAuth1 {
+                consumer_key: consumer_key.to_string(),
+                consumer_key_secret: consumer_key_secret.to_string(),
+                access_token: access_token.to_string(),
+                access_token_secret: access_token_secret.to_string(),
+            },
+            http_client: Client::new(),
         }
     }
+
+    /// Creates a new `TweetyClient` authenticated with a static OAuth 2.0
+    /// app-only bearer token, for read-only endpoints that don't need a
+    /// per-user context.
+    pub fn with_bearer_token(bearer_token: &str) -> Self {
+        TweetyClient {
+            credentials: Credentials::Bearer(bearer_token.to_string()),
+            http_client: Client::new(),
+        }
+    }
+
+    /// Creates a new `TweetyClient` by exchanging an app's consumer
+    /// key/secret for an OAuth 2.0 app-only bearer token via the
+    /// client-credentials grant.
+    pub async fn from_app_only(
+        consumer_key: &str,
+        consumer_key_secret: &str,
+    ) -> Result<Self, TweetyError> {
+        let credentials = Credentials::from_app_only(consumer_key, consumer_key_secret).await?;
+        Ok(TweetyClient {
+            credentials,
+            http_client: Client::new(),
+        })
+    }
+
+    /// Starts building a `TweetyClient` backed by a shared, connection-pooled
+    /// [`reqwest::Client`] tuned via [`TweetyClientBuilder`], instead of the
+    /// default client `TweetyClient::new` constructs.
+    pub fn builder() -> TweetyClientBuilder {
+        TweetyClientBuilder::new()
+    }
+
     pub fn is_initialized(&self) -> bool {
-        !self.consumer_key.is_empty()
-            && !self.access_token.is_empty()
-            && !self.consumer_key_secret.is_empty()
-            && !self.access_token_secret.is_empty()
+        self.credentials.is_initialized()
+    }
+
+    pub(crate) async fn dispatch(
+        &self,
+        parsed_url: &str,
+        method: Method,
+        json_body: String,
+    ) -> Result<reqwest::Response, TweetyError> {
+        let client = self.http_client.clone();
+
+        match &self.credentials {
+            Credentials::OAuth1 {
+                consumer_key,
+                consumer_key_secret,
+                access_token,
+                access_token_secret,
+            } => {
+                let secrets = reqwest_oauth1::Secrets::new(consumer_key, consumer_key_secret)
+                    .token(access_token, access_token_secret);
+
+                match method {
+                    Method::POST => {
+                        client
+                            .oauth1(secrets)
+                            .post(parsed_url)
+                            .header("Content-Type", "application/json")
+                            .body(json_body)
+                            .send()
+                            .await
+                    }
+                    Method::GET => client.oauth1(secrets).get(parsed_url).send().await,
+                    Method::DELETE => client.oauth1(secrets).delete(parsed_url).send().await,
+                    Method::PUT => client.oauth1(secrets).put(parsed_url).send().await,
+                    _ => panic!("Method not allowed"),
+                }
+                .map_err(|err| TweetyError::NetworkError(err.to_string()))
+            }
+            Credentials::Bearer(token) => match method {
+                Method::POST => {
+                    client
+                        .post(parsed_url)
+                        .bearer_auth(token)
+                        .header("Content-Type", "application/json")
+                        .body(json_body)
+                        .send()
+                        .await
+                }
+                Method::GET => client.get(parsed_url).bearer_auth(token).send().await,
+                Method::DELETE => client.delete(parsed_url).bearer_auth(token).send().await,
+                Method::PUT => client.put(parsed_url).bearer_auth(token).send().await,
+                _ => panic!("Method not allowed"),
+            }
+            .map_err(|err| TweetyError::NetworkError(err.to_string())),
+        }
+    }
+
+    async fn dispatch_multipart(
+        &self,
+        parsed_url: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<reqwest::Response, TweetyError> {
+        let client = self.http_client.clone();
+
+        match &self.credentials {
+            Credentials::OAuth1 {
+                consumer_key,
+                consumer_key_secret,
+                access_token,
+                access_token_secret,
+            } => {
+                let secrets = reqwest_oauth1::Secrets::new(consumer_key, consumer_key_secret)
+                    .token(access_token, access_token_secret);
+
+                client
+                    .oauth1(secrets)
+                    .post(parsed_url)
+                    .multipart(form)
+                    .send()
+                    .await
+                    .map_err(|err| TweetyError::NetworkError(err.to_string()))
+            }
+            Credentials::Bearer(token) => client
+                .post(parsed_url)
+                .bearer_auth(token)
+                .multipart(form)
+                .send()
+                .await
+                .map_err(|err| TweetyError::NetworkError(err.to_string())),
+        }
+    }
+
+    /// Like [`TweetyClient::send_request`], but for endpoints that take a
+    /// `multipart/form-data` body (e.g. chunked media upload) rather than a
+    /// JSON-encoded one.
+    pub(crate) async fn send_multipart_request(
+        &self,
+        url: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<Value, TweetyError> {
+        if !self.is_initialized() {
+            return Err(TweetyError::MissingCredentials);
+        };
+
+        let parsed_url = match Url::parse(url) {
+            Ok(url) => url.to_string(),
+            Err(err) => {
+                return Err(TweetyError::UrlParseError(err));
+            }
+        };
+
+        let response = self.dispatch_multipart(&parsed_url, form).await?;
+
+        if response.status().is_success() {
+            // Twitter's APPEND step responds 2xx with an empty body, so an
+            // empty body is treated as success rather than a parse error.
+            let text = response
+                .text()
+                .await
+                .map_err(|err| TweetyError::NetworkError(err.to_string()))?;
+
+            if text.is_empty() {
+                return Ok(Value::Null);
+            }
+
+            return serde_json::from_str::<Value>(&text)
+                .map_err(|err| TweetyError::JsonParseError(err.to_string()));
+        }
+        let status = response.status();
+
+        let status_text = response
+            .json::<Value>()
+            .await
+            .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
+
+        Err(TweetyError::ApiError(format!(
+            "HTTP {}: {}",
+            status, status_text
+        )))
     }
+
+    // Used by the higher-level tweet/timeline endpoints built on top of
+    // this client; not called directly from the modules vendored here.
+    #[allow(dead_code)]
     pub(crate) async fn send_request<T>(
         &self,
         url: &str,
@@ -78,53 +253,33 @@ impl TweetyClient {
             }
         };
 
-        let secrets = reqwest_oauth1::Secrets::new(&self.consumer_key, &self.consumer_key_secret)
-            .token(&self.access_token, &self.access_token_secret);
-
-        let client = Client::new();
         let mut json_body = String::new();
 
         if body.is_some() {
             json_body = serde_json::to_string(&body).unwrap();
         }
 
-        let response = match method {
-            Method::POST => client
-                .oauth1(secrets)
-                .post(&parsed_url)
-                .header("Content-Type", "application/json")
-                .body(json_body)
-                .send(),
-            Method::GET => client.oauth1(secrets).get(&parsed_url).send(),
-            Method::DELETE => client.oauth1(secrets).delete(&parsed_url).send(),
-            Method::PUT => client.oauth1(secrets).put(&parsed_url).send(),
-            _ => panic!("Method not allowed"),
-        };
+        let response = self.dispatch(&parsed_url, method, json_body).await?;
 
-        match response.await {
-            Ok(response) => {
-                if response.status().is_success() {
-                    let api_response = response
-                        .json::<Value>()
-                        .await
-                        .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
+        if response.status().is_success() {
+            let api_response = response
+                .json::<Value>()
+                .await
+                .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
 
-                    return Ok(api_response);
-                }
-                let status = response.status();
+            return Ok(api_response);
+        }
+        let status = response.status();
 
-                let status_text = response
-                    .json::<Value>()
-                    .await
-                    .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
+        let status_text = response
+            .json::<Value>()
+            .await
+            .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
 
-                Err(TweetyError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, status_text
-                )))
-            }
-            Err(err) => Err(TweetyError::NetworkError(err.to_string())),
-        }
+        Err(TweetyError::ApiError(format!(
+            "HTTP {}: {}",
+            status, status_text
+        )))
     }
 
     pub(crate) async fn send_request_with_headers<T>(
@@ -147,57 +302,44 @@ impl TweetyClient {
             }
         };
 
-        let secrets = reqwest_oauth1::Secrets::new(&self.consumer_key, &self.consumer_key_secret)
-            .token(&self.access_token, &self.access_token_secret);
-
-        let client = Client::new();
         let mut json_body = String::new();
 
         if body.is_some() {
             json_body = serde_json::to_string(&body).unwrap();
         }
 
-        let response = match method {
-            Method::POST => client
-                .oauth1(secrets)
-                .post(&parsed_url)
-                .header("Content-Type", "application/json")
-                .body(json_body)
-                .send(),
-            Method::GET => client.oauth1(secrets).get(&parsed_url).send(),
-            Method::DELETE => client.oauth1(secrets).delete(&parsed_url).send(),
-            Method::PUT => client.oauth1(secrets).put(&parsed_url).send(),
-            _ => panic!("Method not allowed"),
-        };
+        let response = self.dispatch(&parsed_url, method, json_body).await?;
 
-        match response.await {
-            Ok(response) => {
-                if response.status().is_success() {
-                    let headers = response.headers().clone();
-                    let api_response = response
-                        .json::<Value>()
-                        .await
-                        .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
+        if response.status().is_success() {
+            let headers = response.headers().clone();
+            let api_response = response
+                .json::<Value>()
+                .await
+                .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
 
-                    return Ok(ResponseWithHeaders {
-                        body: api_response,
-                        headers: headers,
-                    });
-                }
-                let status = response.status();
-                let headers = response.headers().clone();
-
-                let status_text = response
-                    .json::<Value>()
-                    .await
-                    .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
+            return Ok(ResponseWithHeaders {
+                body: api_response,
+                headers,
+            });
+        }
+        let status = response.status();
+        let headers = response.headers().clone();
 
-                Err(TweetyError::ApiError(format!(
-                    "HTTP {}: {}:{:?}",
-                    status, status_text, headers
-                )))
-            }
-            Err(err) => Err(TweetyError::NetworkError(err.to_string())),
+        // Report 429s ahead of body parsing: a rate-limited response isn't
+        // guaranteed to carry a JSON body, but the `x-rate-limit-*` headers
+        // are always present and callers need them to back off correctly.
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(TweetyError::TooManyRequests(headers));
         }
+
+        let status_text = response
+            .json::<Value>()
+            .await
+            .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
+
+        Err(TweetyError::ApiError(format!(
+            "HTTP {}: {}:{:?}",
+            status, status_text, headers
+        )))
     }
 }
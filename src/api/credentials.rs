@@ -0,0 +1,79 @@
+use crate::api::error::TweetyError;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const APP_ONLY_TOKEN_URL: &str = "https://api.twitter.com/oauth2/token";
+
+/// The authentication material a [`crate::api::client::TweetyClient`] signs
+/// its requests with.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum Credentials {
+    /// Three-legged OAuth 1.0a, signing each request with the consumer and
+    /// access token/secret pairs.
+    OAuth1 {
+        consumer_key: String,
+        consumer_key_secret: String,
+        access_token: String,
+        access_token_secret: String,
+    },
+    /// OAuth 2.0 app-only authentication, sent as a static
+    /// `Authorization: Bearer <token>` header. Suitable for read-only
+    /// endpoints that don't need a per-user context.
+    Bearer(String),
+}
+
+impl Credentials {
+    pub(crate) fn is_initialized(&self) -> bool {
+        match self {
+            Credentials::OAuth1 {
+                consumer_key,
+                consumer_key_secret,
+                access_token,
+                access_token_secret,
+            } => {
+                !consumer_key.is_empty()
+                    && !consumer_key_secret.is_empty()
+                    && !access_token.is_empty()
+                    && !access_token_secret.is_empty()
+            }
+            Credentials::Bearer(token) => !token.is_empty(),
+        }
+    }
+
+    /// Exchanges an app's consumer key/secret for an OAuth 2.0 app-only
+    /// bearer token via the client-credentials grant, for read-only use
+    /// cases that don't need per-user tokens.
+    pub async fn from_app_only(
+        consumer_key: &str,
+        consumer_key_secret: &str,
+    ) -> Result<Self, TweetyError> {
+        let basic = STANDARD.encode(format!("{}:{}", consumer_key, consumer_key_secret));
+
+        let client = Client::new();
+        let response = client
+            .post(APP_ONLY_TOKEN_URL)
+            .header("Authorization", format!("Basic {}", basic))
+            .form(&[("grant_type", "client_credentials")])
+            .send()
+            .await
+            .map_err(|err| TweetyError::NetworkError(err.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(TweetyError::ApiError(format!("HTTP {}", response.status())));
+        }
+
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
+
+        let access_token = body
+            .get("access_token")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| TweetyError::AuthError("missing access_token in response".to_string()))?;
+
+        Ok(Credentials::Bearer(access_token.to_string()))
+    }
+}
@@ -0,0 +1,34 @@
+use reqwest::header::HeaderMap;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum TweetyError {
+    MissingCredentials,
+    UrlParseError(url::ParseError),
+    JsonParseError(String),
+    NetworkError(String),
+    ApiError(String),
+    AuthError(String),
+    ClientBuildError(String),
+    /// The endpoint responded `429 Too Many Requests`, reported ahead of
+    /// parsing the response body so callers can always read the
+    /// `x-rate-limit-*` headers even when the body isn't valid JSON.
+    TooManyRequests(HeaderMap),
+}
+
+impl fmt::Display for TweetyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TweetyError::MissingCredentials => write!(f, "missing credentials"),
+            TweetyError::UrlParseError(err) => write!(f, "failed to parse url: {}", err),
+            TweetyError::JsonParseError(err) => write!(f, "failed to parse json response: {}", err),
+            TweetyError::NetworkError(err) => write!(f, "network error: {}", err),
+            TweetyError::ApiError(err) => write!(f, "api error: {}", err),
+            TweetyError::AuthError(err) => write!(f, "authentication error: {}", err),
+            TweetyError::ClientBuildError(err) => write!(f, "failed to build http client: {}", err),
+            TweetyError::TooManyRequests(_) => write!(f, "rate limited (HTTP 429)"),
+        }
+    }
+}
+
+impl std::error::Error for TweetyError {}
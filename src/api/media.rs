@@ -0,0 +1,82 @@
+use crate::api::client::TweetyClient;
+use crate::api::error::TweetyError;
+use reqwest::multipart::{Form, Part};
+
+const MEDIA_UPLOAD_URL: &str = "https://upload.twitter.com/1.1/media/upload.json";
+/// Twitter's recommended chunk size for the APPEND step.
+const SEGMENT_SIZE: usize = 5 * 1024 * 1024;
+
+/// The `media_id` Twitter assigns an uploaded file, ready to attach to a
+/// tweet's `media.media_ids`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaId(pub String);
+
+impl TweetyClient {
+    /// Uploads an image or video via Twitter's three-phase chunked upload
+    /// protocol, returning the [`MediaId`] to attach to a tweet.
+    ///
+    /// The file is INIT'd with its total size and MIME `media_type`, sent in
+    /// ~5 MB APPEND chunks, and FINALIZE'd once every chunk has landed.
+    pub async fn upload_media(
+        &self,
+        bytes: &[u8],
+        media_type: &str,
+    ) -> Result<MediaId, TweetyError> {
+        let media_id = self.init_media_upload(bytes.len(), media_type).await?;
+
+        for (segment_index, chunk) in bytes.chunks(SEGMENT_SIZE).enumerate() {
+            self.append_media_upload(&media_id, segment_index, chunk)
+                .await?;
+        }
+
+        self.finalize_media_upload(&media_id).await?;
+
+        Ok(MediaId(media_id))
+    }
+
+    async fn init_media_upload(
+        &self,
+        total_bytes: usize,
+        media_type: &str,
+    ) -> Result<String, TweetyError> {
+        let form = Form::new()
+            .text("command", "INIT")
+            .text("total_bytes", total_bytes.to_string())
+            .text("media_type", media_type.to_string());
+
+        let response = self.send_multipart_request(MEDIA_UPLOAD_URL, form).await?;
+
+        response
+            .get("media_id_string")
+            .and_then(|value| value.as_str())
+            .map(|media_id| media_id.to_string())
+            .ok_or_else(|| {
+                TweetyError::ApiError("missing media_id_string in INIT response".to_string())
+            })
+    }
+
+    async fn append_media_upload(
+        &self,
+        media_id: &str,
+        segment_index: usize,
+        chunk: &[u8],
+    ) -> Result<(), TweetyError> {
+        let form = Form::new()
+            .text("command", "APPEND")
+            .text("media_id", media_id.to_string())
+            .text("segment_index", segment_index.to_string())
+            .part("media", Part::bytes(chunk.to_vec()));
+
+        self.send_multipart_request(MEDIA_UPLOAD_URL, form).await?;
+        Ok(())
+    }
+
+    async fn finalize_media_upload(&self, media_id: &str) -> Result<(), TweetyError> {
+        let form = Form::new()
+            .text("command", "FINALIZE")
+            .text("media_id", media_id.to_string());
+
+        self.send_multipart_request(MEDIA_UPLOAD_URL, form).await?;
+        Ok(())
+    }
+}
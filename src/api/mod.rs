@@ -0,0 +1,8 @@
+pub mod auth;
+pub mod builder;
+pub mod client;
+pub mod credentials;
+pub mod error;
+pub mod media;
+pub mod rate_limit;
+pub mod streaming;
@@ -0,0 +1,102 @@
+use crate::api::client::{ResponseWithHeaders, TweetyClient};
+use crate::api::error::TweetyError;
+use reqwest::{header::HeaderMap, Method};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::sleep;
+
+/// A snapshot of Twitter's per-endpoint rate-limit window, parsed from the
+/// `x-rate-limit-*` response headers.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset: u64,
+}
+
+impl RateLimit {
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        Some(RateLimit {
+            limit: header_u64(headers, "x-rate-limit-limit")?,
+            remaining: header_u64(headers, "x-rate-limit-remaining")?,
+            reset: header_u64(headers, "x-rate-limit-reset")?,
+        })
+    }
+
+    /// How long to wait for the window to reset, relative to now.
+    fn wait_duration(&self) -> Duration {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Duration::from_secs(self.reset.saturating_sub(now))
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.parse().ok()
+}
+
+impl TweetyClient {
+    /// Like [`TweetyClient::send_request`], but also returns the caller's
+    /// remaining rate-limit budget for the endpoint, parsed from the
+    /// response headers.
+    pub async fn send_request_tracked<T>(
+        &self,
+        url: &str,
+        method: Method,
+        body: Option<T>,
+    ) -> Result<(Value, RateLimit), TweetyError>
+    where
+        T: Serialize + Deserialize<'static>,
+    {
+        let ResponseWithHeaders { body, headers } =
+            self.send_request_with_headers(url, method, body).await?;
+
+        let rate_limit = RateLimit::from_headers(&headers)
+            .ok_or_else(|| TweetyError::ApiError("missing rate-limit headers".to_string()))?;
+
+        Ok((body, rate_limit))
+    }
+
+    /// Same as [`TweetyClient::send_request`], but when Twitter responds
+    /// with `429 Too Many Requests`, sleeps until the window resets (capped
+    /// by `max_wait`) and retries instead of returning an error. A
+    /// successful response is always returned as-is, even if it reports the
+    /// window is now exhausted — that only affects the *next* call.
+    pub async fn send_request_with_backoff<T>(
+        &self,
+        url: &str,
+        method: Method,
+        body: Option<T>,
+        max_wait: Option<Duration>,
+    ) -> Result<Value, TweetyError>
+    where
+        T: Serialize + Deserialize<'static> + Clone,
+    {
+        loop {
+            match self
+                .send_request_with_headers(url, method.clone(), body.clone())
+                .await
+            {
+                Ok(ResponseWithHeaders { body: value, .. }) => return Ok(value),
+                Err(TweetyError::TooManyRequests(headers)) => {
+                    let wait = RateLimit::from_headers(&headers)
+                        .map(|rate_limit| rate_limit.wait_duration())
+                        .unwrap_or(Duration::from_secs(60));
+                    sleep(cap_wait(wait, max_wait)).await;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+fn cap_wait(wait: Duration, max_wait: Option<Duration>) -> Duration {
+    match max_wait {
+        Some(max) => wait.min(max),
+        None => wait,
+    }
+}
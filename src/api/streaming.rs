@@ -0,0 +1,76 @@
+use crate::api::client::TweetyClient;
+use crate::api::error::TweetyError;
+use async_stream::try_stream;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::Url;
+
+impl TweetyClient {
+    /// Opens a long-lived connection to a streaming endpoint (e.g. the
+    /// filtered-stream or user-stream APIs) and yields each tweet as it
+    /// arrives, instead of waiting for the response to complete.
+    ///
+    /// Twitter's streaming endpoints deliver newline-delimited JSON objects
+    /// separated by `\r\n`, interspersed with bare `\r` keep-alive records
+    /// that carry no data. Those keep-alives are swallowed rather than
+    /// surfaced to the caller, and objects that straddle two network chunks
+    /// are buffered until they're complete.
+    pub fn send_streaming_request<'a, T>(
+        &'a self,
+        url: &str,
+        method: Method,
+        body: Option<T>,
+    ) -> impl Stream<Item = Result<Value, TweetyError>> + 'a
+    where
+        T: Serialize + Deserialize<'static> + 'a,
+    {
+        let url = url.to_string();
+        try_stream! {
+            if !self.is_initialized() {
+                Err(TweetyError::MissingCredentials)?;
+            }
+
+            let parsed_url = Url::parse(&url)
+                .map_err(TweetyError::UrlParseError)?
+                .to_string();
+
+            let mut json_body = String::new();
+            if body.is_some() {
+                json_body = serde_json::to_string(&body).unwrap();
+            }
+
+            let response = self.dispatch(&parsed_url, method, json_body).await?;
+
+            if !response.status().is_success() {
+                Err(TweetyError::ApiError(format!("HTTP {}", response.status())))?;
+            }
+
+            let mut byte_stream = response.bytes_stream();
+            let mut buffer: Vec<u8> = Vec::new();
+
+            while let Some(chunk) = byte_stream.next().await {
+                let chunk = chunk.map_err(|err| TweetyError::NetworkError(err.to_string()))?;
+                buffer.extend_from_slice(&chunk);
+
+                while let Some(pos) = buffer.iter().position(|&b| b == b'\r') {
+                    let line: Vec<u8> = buffer.drain(..=pos).collect();
+                    let line = &line[..line.len() - 1];
+
+                    // Keep-alives decode to an empty line, or to just the
+                    // `\n` left over from the previous `\r\n` separator;
+                    // skip them instead of treating them as end-of-stream.
+                    if line.iter().all(|b| b.is_ascii_whitespace()) {
+                        continue;
+                    }
+
+                    let value = serde_json::from_slice::<Value>(line)
+                        .map_err(|err| TweetyError::JsonParseError(err.to_string()))?;
+                    yield value;
+                }
+            }
+        }
+    }
+}